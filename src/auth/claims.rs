@@ -0,0 +1,84 @@
+// JWT claims and the `AccessClaims` extractor
+
+use axum::extract::{FromRef, FromRequestParts};
+use axum::http::request::Parts;
+use axum::http::{header, HeaderMap};
+use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
+use serde::{Deserialize, Serialize};
+
+use crate::config::JwtSettings;
+use crate::error::Error;
+
+/// Claims carried by the signed JWT issued on login
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Claims {
+    pub sub: String,
+    pub iat: usize,
+    pub exp: usize,
+}
+
+impl Claims {
+    pub fn new(user_id: i32, settings: &JwtSettings) -> Self {
+        let now = chrono::Utc::now();
+        let exp = now + settings.maxage_duration();
+
+        Self {
+            sub: user_id.to_string(),
+            iat: now.timestamp() as usize,
+            exp: exp.timestamp() as usize,
+        }
+    }
+
+    pub fn encode(&self, settings: &JwtSettings) -> Result<String, Error> {
+        encode(
+            &Header::default(),
+            self,
+            &EncodingKey::from_secret(settings.secret.as_bytes()),
+        )
+        .map_err(|_| Error::Unauthorized)
+    }
+
+    fn decode(token: &str, settings: &JwtSettings) -> Result<Self, Error> {
+        decode::<Claims>(
+            token,
+            &DecodingKey::from_secret(settings.secret.as_bytes()),
+            &Validation::default(),
+        )
+        .map(|data| data.claims)
+        .map_err(|_| Error::Unauthorized)
+    }
+}
+
+/// Pull and validate the bearer token from the request headers
+pub fn claims_from_headers(headers: &HeaderMap, settings: &JwtSettings) -> Result<Claims, Error> {
+    let token = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .ok_or(Error::Unauthorized)?;
+
+    Claims::decode(token, settings)
+}
+
+/// Extractor that validates the `Authorization: Bearer` header against the
+/// configured JWT secret and yields the caller's identity
+#[derive(Debug, Clone)]
+pub struct AccessClaims {
+    pub user_id: i32,
+}
+
+impl<S> FromRequestParts<S> for AccessClaims
+where
+    JwtSettings: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = Error;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let settings = JwtSettings::from_ref(state);
+        let claims = claims_from_headers(&parts.headers, &settings)?;
+        let user_id = claims.sub.parse::<i32>().map_err(|_| Error::Unauthorized)?;
+
+        Ok(AccessClaims { user_id })
+    }
+}
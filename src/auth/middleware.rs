@@ -0,0 +1,21 @@
+// Route protection: reject requests without a valid bearer token
+
+use axum::extract::{Request, State};
+use axum::middleware::Next;
+use axum::response::Response;
+
+use crate::config::JwtSettings;
+use crate::error::Error;
+
+use super::claims::claims_from_headers;
+
+/// Require a valid `Authorization: Bearer` token before reaching the
+/// wrapped routes. Apply with `route_layer(middleware::from_fn_with_state(...))`.
+pub async fn require_auth(
+    State(settings): State<JwtSettings>,
+    request: Request,
+    next: Next,
+) -> Result<Response, Error> {
+    claims_from_headers(request.headers(), &settings)?;
+    Ok(next.run(request).await)
+}
@@ -0,0 +1,7 @@
+// JWT authentication subsystem
+pub mod claims;
+pub mod middleware;
+pub mod password;
+
+pub use claims::{AccessClaims, Claims};
+pub use middleware::require_auth;
@@ -0,0 +1,23 @@
+// Argon2 password hashing
+
+use argon2::password_hash::{rand_core::OsRng, PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::Argon2;
+
+use crate::error::Error;
+
+/// Hash a plaintext password for storage
+pub fn hash_password(password: &str) -> Result<String, Error> {
+    let salt = SaltString::generate(&mut OsRng);
+    Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map(|hash| hash.to_string())
+        .map_err(|e| Error::Internal(format!("failed to hash password: {}", e)))
+}
+
+/// Verify a plaintext password against a stored Argon2 hash
+pub fn verify_password(password: &str, hash: &str) -> Result<(), Error> {
+    let parsed_hash = PasswordHash::new(hash).map_err(|_| Error::Unauthorized)?;
+    Argon2::default()
+        .verify_password(password.as_bytes(), &parsed_hash)
+        .map_err(|_| Error::Unauthorized)
+}
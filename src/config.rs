@@ -0,0 +1,165 @@
+// Layered application configuration
+//
+// Configuration is loaded from `configuration/base.yaml`, overlaid with an
+// environment-specific file (`configuration/{local,production}.yaml`)
+// selected via `APP_ENVIRONMENT`, and finally overridden by `APP_`-prefixed
+// environment variables (e.g. `APP_APPLICATION__PORT=8080`).
+
+use serde::Deserialize;
+use sqlx::postgres::{PgConnectOptions, PgSslMode};
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct Settings {
+    pub database: DatabaseSettings,
+    pub application: ApplicationSettings,
+    pub jwt: JwtSettings,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ApplicationSettings {
+    pub host: String,
+    pub port: u16,
+}
+
+impl ApplicationSettings {
+    pub fn address(&self) -> String {
+        format!("{}:{}", self.host, self.port)
+    }
+}
+
+#[derive(Deserialize, Clone)]
+pub struct DatabaseSettings {
+    pub host: String,
+    pub port: u16,
+    pub username: String,
+    pub password: String,
+    pub database_name: String,
+    pub max_connections: u32,
+    pub require_ssl: bool,
+}
+
+/// Manual `Debug` impl so `password` never ends up in logs, traces, or
+/// panic messages - `#[tracing::instrument]` is pervasive in `handlers`
+/// and would otherwise happily print it via `{:?}`.
+impl std::fmt::Debug for DatabaseSettings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DatabaseSettings")
+            .field("host", &self.host)
+            .field("port", &self.port)
+            .field("username", &self.username)
+            .field("password", &"[REDACTED]")
+            .field("database_name", &self.database_name)
+            .field("max_connections", &self.max_connections)
+            .field("require_ssl", &self.require_ssl)
+            .finish()
+    }
+}
+
+impl DatabaseSettings {
+    /// Connection options scoped to the maintenance database (no
+    /// `database_name`) - used for creating databases.
+    pub fn without_db(&self) -> PgConnectOptions {
+        let ssl_mode = if self.require_ssl {
+            PgSslMode::Require
+        } else {
+            PgSslMode::Prefer
+        };
+
+        PgConnectOptions::new()
+            .host(&self.host)
+            .port(self.port)
+            .username(&self.username)
+            .password(&self.password)
+            .ssl_mode(ssl_mode)
+    }
+
+    /// Connection options for the application database
+    pub fn with_db(&self) -> PgConnectOptions {
+        self.without_db().database(&self.database_name)
+    }
+}
+
+#[derive(Deserialize, Clone)]
+pub struct JwtSettings {
+    pub secret: String,
+    pub expires_in: String,
+    pub maxage: i64,
+}
+
+/// Manual `Debug` impl so the signing `secret` never ends up in logs,
+/// traces, or panic messages - see [`DatabaseSettings`]'s impl for the
+/// same rationale.
+impl std::fmt::Debug for JwtSettings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JwtSettings")
+            .field("secret", &"[REDACTED]")
+            .field("expires_in", &self.expires_in)
+            .field("maxage", &self.maxage)
+            .finish()
+    }
+}
+
+impl JwtSettings {
+    /// Token lifetime used to compute `Claims::exp`, in minutes
+    pub fn maxage_duration(&self) -> chrono::Duration {
+        chrono::Duration::minutes(self.maxage)
+    }
+}
+
+/// Which deployment environment the app is running in
+pub enum Environment {
+    Local,
+    Production,
+}
+
+impl Environment {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Environment::Local => "local",
+            Environment::Production => "production",
+        }
+    }
+}
+
+impl TryFrom<String> for Environment {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        match s.to_lowercase().as_str() {
+            "local" => Ok(Self::Local),
+            "production" => Ok(Self::Production),
+            other => Err(format!(
+                "{} is not a supported environment. Use either `local` or `production`.",
+                other
+            )),
+        }
+    }
+}
+
+/// Load `Settings` from `configuration/base.yaml` plus the environment
+/// overlay selected by `APP_ENVIRONMENT` (defaults to `local`), with
+/// `APP_`-prefixed environment variables taking precedence over both.
+pub fn get_configuration() -> Result<Settings, config::ConfigError> {
+    let base_path = std::env::current_dir().expect("Failed to determine the current directory");
+    let configuration_directory = base_path.join("configuration");
+
+    let environment: Environment = std::env::var("APP_ENVIRONMENT")
+        .unwrap_or_else(|_| "local".into())
+        .try_into()
+        .expect("Failed to parse APP_ENVIRONMENT");
+    let environment_filename = format!("{}.yaml", environment.as_str());
+
+    let settings = config::Config::builder()
+        .add_source(config::File::from(configuration_directory.join("base.yaml")))
+        .add_source(config::File::from(
+            configuration_directory.join(environment_filename),
+        ))
+        .add_source(
+            config::Environment::with_prefix("APP")
+                .prefix_separator("_")
+                .separator("__"),
+        )
+        .build()?;
+
+    settings.try_deserialize::<Settings>()
+}
@@ -3,21 +3,20 @@
 use sqlx::{postgres::PgPoolOptions, PgPool};
 use std::time::Duration;
 
-/// Create a PostgreSQL connection pool
-/// 
+use crate::config::DatabaseSettings;
+
+/// Create a PostgreSQL connection pool from the given `DatabaseSettings`
+///
 /// This function:
-/// - Reads DATABASE_URL from environment variables
-/// - Creates a connection pool with max 5 connections
+/// - Builds connection options from the layered configuration
+/// - Creates a connection pool with the configured `max_connections`
 /// - Sets connection timeout to 30 seconds
 /// - Returns the pool for use throughout the app
-pub async fn create_pool() -> Result<PgPool, sqlx::Error> {
-    let database_url = std::env::var("DATABASE_URL")
-        .expect("DATABASE_URL must be set in .env file");
-
+pub async fn create_pool(settings: &DatabaseSettings) -> Result<PgPool, sqlx::Error> {
     let pool = PgPoolOptions::new()
-        .max_connections(5)
+        .max_connections(settings.max_connections)
         .acquire_timeout(Duration::from_secs(30))
-        .connect(&database_url)
+        .connect_with(settings.with_db())
         .await?;
 
     println!("✅ Database connection pool created");
@@ -0,0 +1,6 @@
+// Domain newtypes - guard invariants at construction time
+mod user_email;
+mod user_name;
+
+pub use user_email::UserEmail;
+pub use user_name::UserName;
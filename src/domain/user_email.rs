@@ -0,0 +1,61 @@
+use validator::ValidateEmail;
+
+use crate::error::Error;
+
+/// A user's email address, validated on construction via the `validator` crate
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserEmail(String);
+
+impl UserEmail {
+    pub fn parse(email: String) -> Result<Self, Error> {
+        if email.validate_email() {
+            Ok(Self(email))
+        } else {
+            Err(Error::Validation(format!(
+                "{} is not a valid email address",
+                email
+            )))
+        }
+    }
+}
+
+impl AsRef<str> for UserEmail {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for UserEmail {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UserEmail;
+
+    #[test]
+    fn empty_string_is_rejected() {
+        let email = "".to_string();
+        assert!(UserEmail::parse(email).is_err());
+    }
+
+    #[test]
+    fn missing_at_symbol_is_rejected() {
+        let email = "ursuladomain.com".to_string();
+        assert!(UserEmail::parse(email).is_err());
+    }
+
+    #[test]
+    fn missing_local_part_is_rejected() {
+        let email = "@domain.com".to_string();
+        assert!(UserEmail::parse(email).is_err());
+    }
+
+    #[test]
+    fn a_valid_email_is_parsed_successfully() {
+        let email = "ursula@domain.com".to_string();
+        assert!(UserEmail::parse(email).is_ok());
+    }
+}
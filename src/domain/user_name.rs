@@ -0,0 +1,81 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::error::Error;
+
+/// A user's display name, validated on construction
+///
+/// Rejects empty/whitespace-only values, values longer than 256 graphemes,
+/// and strings containing any of `/ ( ) " < > \ { }`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserName(String);
+
+impl UserName {
+    pub fn parse(name: String) -> Result<Self, Error> {
+        let is_empty_or_whitespace = name.trim().is_empty();
+        let is_too_long = name.graphemes(true).count() > 256;
+        let forbidden_characters = ['/', '(', ')', '"', '<', '>', '\\', '{', '}'];
+        let contains_forbidden_characters =
+            name.chars().any(|c| forbidden_characters.contains(&c));
+
+        if is_empty_or_whitespace || is_too_long || contains_forbidden_characters {
+            return Err(Error::Validation(format!("{} is not a valid name", name)));
+        }
+
+        Ok(Self(name))
+    }
+}
+
+impl AsRef<str> for UserName {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for UserName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UserName;
+
+    #[test]
+    fn a_256_grapheme_long_name_is_valid() {
+        let name = "a".repeat(256);
+        assert!(UserName::parse(name).is_ok());
+    }
+
+    #[test]
+    fn a_name_longer_than_256_graphemes_is_rejected() {
+        let name = "a".repeat(257);
+        assert!(UserName::parse(name).is_err());
+    }
+
+    #[test]
+    fn whitespace_only_names_are_rejected() {
+        let name = "   ".to_string();
+        assert!(UserName::parse(name).is_err());
+    }
+
+    #[test]
+    fn empty_string_is_rejected() {
+        let name = "".to_string();
+        assert!(UserName::parse(name).is_err());
+    }
+
+    #[test]
+    fn names_containing_forbidden_characters_are_rejected() {
+        for name in ['/', '(', ')', '"', '<', '>', '\\', '{', '}'] {
+            let name = name.to_string();
+            assert!(UserName::parse(name).is_err());
+        }
+    }
+
+    #[test]
+    fn a_valid_name_is_parsed_successfully() {
+        let name = "Ursula Le Guin".to_string();
+        assert!(UserName::parse(name).is_ok());
+    }
+}
@@ -0,0 +1,74 @@
+// Crate-wide error type and its HTTP representation
+
+use axum::{http::StatusCode, response::IntoResponse, response::Response, Json};
+use serde_json::json;
+
+/// Errors that can occur anywhere in the application
+///
+/// Handlers return `Result<T, Error>` and use `?` on fallible calls (e.g.
+/// `sqlx` queries) instead of hand-rolling `(StatusCode, String)` tuples.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("resource not found")]
+    NotFound,
+
+    #[error("email already exists")]
+    DuplicateEmail,
+
+    #[error("validation error: {0}")]
+    Validation(String),
+
+    #[error("invalid credentials")]
+    Unauthorized,
+
+    #[error("forbidden")]
+    Forbidden,
+
+    #[error("internal error")]
+    Internal(String),
+
+    #[error(transparent)]
+    Database(#[from] sqlx::Error),
+}
+
+impl IntoResponse for Error {
+    fn into_response(self) -> Response {
+        let (status, message) = match &self {
+            Error::NotFound => (StatusCode::NOT_FOUND, self.to_string()),
+            Error::DuplicateEmail => (StatusCode::CONFLICT, self.to_string()),
+            Error::Validation(msg) => (StatusCode::UNPROCESSABLE_ENTITY, msg.clone()),
+            Error::Unauthorized => (StatusCode::UNAUTHORIZED, self.to_string()),
+            Error::Forbidden => (StatusCode::FORBIDDEN, self.to_string()),
+            Error::Internal(msg) => {
+                tracing::error!(error = %msg, "internal error");
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "internal server error".to_string(),
+                )
+            }
+            Error::Database(sqlx::Error::RowNotFound) => {
+                (StatusCode::NOT_FOUND, "resource not found".to_string())
+            }
+            Error::Database(e) if is_unique_violation(e) => {
+                (StatusCode::CONFLICT, "email already exists".to_string())
+            }
+            Error::Database(e) => {
+                tracing::error!(error = ?e, "database error");
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "internal server error".to_string(),
+                )
+            }
+        };
+
+        (status, Json(json!({ "error": message }))).into_response()
+    }
+}
+
+/// Postgres SQLSTATE `23505` is a unique-constraint violation
+fn is_unique_violation(e: &sqlx::Error) -> bool {
+    e.as_database_error()
+        .and_then(|d| d.code())
+        .as_deref()
+        == Some("23505")
+}
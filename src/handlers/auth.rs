@@ -0,0 +1,51 @@
+// Auth handler - login and token issuance
+
+use axum::{extract::State, http::StatusCode, Json};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+
+use crate::auth::{password, Claims};
+use crate::config::JwtSettings;
+use crate::error::Error;
+
+#[derive(Debug, Deserialize)]
+pub struct LoginRequest {
+    pub email: String,
+    pub password: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LoginResponse {
+    pub token: String,
+    /// Human-readable token lifetime, e.g. `"60m"`, as configured by `jwt.expires_in`
+    pub expires_in: String,
+}
+
+/// Verify credentials and issue a signed JWT
+/// POST /auth/login
+#[tracing::instrument(name = "login", skip(pool, jwt_settings, payload))]
+pub async fn login(
+    State(pool): State<PgPool>,
+    State(jwt_settings): State<JwtSettings>,
+    Json(payload): Json<LoginRequest>,
+) -> Result<(StatusCode, Json<LoginResponse>), Error> {
+    let (user_id, password_hash): (i32, String) = sqlx::query_as(
+        "SELECT id, password_hash FROM users WHERE email = $1",
+    )
+    .bind(&payload.email)
+    .fetch_optional(&pool)
+    .await?
+    .ok_or(Error::Unauthorized)?;
+
+    password::verify_password(&payload.password, &password_hash)?;
+
+    let token = Claims::new(user_id, &jwt_settings).encode(&jwt_settings)?;
+
+    Ok((
+        StatusCode::OK,
+        Json(LoginResponse {
+            token,
+            expires_in: jwt_settings.expires_in.clone(),
+        }),
+    ))
+}
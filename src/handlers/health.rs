@@ -6,6 +6,7 @@ use sqlx::PgPool;
 
 /// Health check endpoint
 /// Returns server status and database connection status
+#[tracing::instrument(name = "health", skip(pool))]
 pub async fn health(State(pool): State<PgPool>) -> (StatusCode, Json<Value>) {
     // Test database connection with a simple query
     let db_status = match sqlx::query("SELECT 1")
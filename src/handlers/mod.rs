@@ -0,0 +1,4 @@
+// Handler modules
+pub mod auth;
+pub mod health;
+pub mod users;
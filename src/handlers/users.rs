@@ -1,48 +1,56 @@
 // Users handler - CRUD operations for users
 
+use axum::extract::{Path, Query};
 use axum::{extract::State, http::StatusCode, Json};
-use axum::extract::Path;
 use sqlx::PgPool;
+use uuid::Uuid;
 
-use crate::models::{CreateUser, User};
+use crate::auth::{password, AccessClaims};
+use crate::domain::{UserEmail, UserName};
+use crate::error::Error;
+use crate::models::{CreateUser, ListUsersParams, UpdateUser, User, UserList};
+
+/// Maximum number of users returned by a single `list_users` call
+const MAX_LIST_LIMIT: i64 = 100;
+const DEFAULT_LIST_LIMIT: i64 = 20;
 
 /// Create a new user
 /// POST /users
+#[tracing::instrument(name = "create_user", skip(pool, payload), fields(request_id = %Uuid::new_v4()))]
 pub async fn create_user(
     State(pool): State<PgPool>,
     Json(payload): Json<CreateUser>,
-) -> Result<(StatusCode, Json<User>), (StatusCode, String)> {
+) -> Result<(StatusCode, Json<User>), Error> {
+    let name = UserName::parse(payload.name)?;
+    let email = UserEmail::parse(payload.email)?;
+    let password_hash = password::hash_password(&payload.password)?;
+
     // Insert user into database
     let user = sqlx::query_as::<_, User>(
         r#"
-        INSERT INTO users (name, email, age)
-        VALUES ($1, $2, $3)
+        INSERT INTO users (name, email, age, password_hash)
+        VALUES ($1, $2, $3, $4)
         RETURNING id, name, email, age, created_at, updated_at
         "#,
     )
-    .bind(&payload.name)
-    .bind(&payload.email)
+    .bind(name.as_ref())
+    .bind(email.as_ref())
     .bind(payload.age)
+    .bind(password_hash)
     .fetch_one(&pool)
-    .await
-    .map_err(|e| {
-        eprintln!("Database error: {:?}", e);
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            format!("Failed to create user: {}", e),
-        )
-    })?;
+    .await?;
 
     Ok((StatusCode::CREATED, Json(user)))
 }
 
-// getting the user from the server 
+// getting the user from the server
+#[tracing::instrument(name = "get_user", skip(pool), fields(request_id = %Uuid::new_v4(), user_id = %id))]
 pub async fn get_user(
     State(pool): State<PgPool>,
     Path(id) : Path<i32>, // extracting id from the url path
-) -> Result<(StatusCode, Json<User>), (StatusCode, String)> {
+) -> Result<(StatusCode, Json<User>), Error> {
+
 
-    
 
     // fetch user from database
     let user = sqlx::query_as::<_, User>(
@@ -54,14 +62,114 @@ pub async fn get_user(
     )
     .bind(id)
     .fetch_one(&pool)
-    .await
-    .map_err(|e| {
-        eprintln!("Database error: {:?}", e);
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            format!("Failed to get user: {}", e),
-        )
-    })?;
+    .await?;
+
+    Ok((StatusCode::OK, Json(user)))
+}
+
+/// Partially update a user
+/// PATCH /users/:id
+///
+/// Unspecified fields are preserved via `COALESCE($n, column)`. Callers may
+/// only update their own record.
+#[tracing::instrument(name = "update_user", skip(pool, payload), fields(request_id = %Uuid::new_v4(), user_id = %id))]
+pub async fn update_user(
+    State(pool): State<PgPool>,
+    Path(id): Path<i32>,
+    claims: AccessClaims,
+    Json(payload): Json<UpdateUser>,
+) -> Result<(StatusCode, Json<User>), Error> {
+    if claims.user_id != id {
+        return Err(Error::Forbidden);
+    }
+
+    let name = payload.name.map(UserName::parse).transpose()?;
+    let email = payload.email.map(UserEmail::parse).transpose()?;
+
+    let user = sqlx::query_as::<_, User>(
+        r#"
+        UPDATE users
+        SET name = COALESCE($1, name),
+            email = COALESCE($2, email),
+            age = COALESCE($3, age),
+            updated_at = now()
+        WHERE id = $4
+        RETURNING id, name, email, age, created_at, updated_at
+        "#,
+    )
+    .bind(name.as_ref().map(UserName::as_ref))
+    .bind(email.as_ref().map(UserEmail::as_ref))
+    .bind(payload.age)
+    .bind(id)
+    .fetch_one(&pool)
+    .await?;
 
     Ok((StatusCode::OK, Json(user)))
+}
+
+/// Delete a user
+/// DELETE /users/:id
+///
+/// Callers may only delete their own record.
+#[tracing::instrument(name = "delete_user", skip(pool), fields(request_id = %Uuid::new_v4(), user_id = %id))]
+pub async fn delete_user(
+    State(pool): State<PgPool>,
+    Path(id): Path<i32>,
+    claims: AccessClaims,
+) -> Result<StatusCode, Error> {
+    if claims.user_id != id {
+        return Err(Error::Forbidden);
+    }
+
+    let result = sqlx::query("DELETE FROM users WHERE id = $1")
+        .bind(id)
+        .execute(&pool)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(Error::NotFound);
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// List users with offset-based pagination
+/// GET /users?limit=&offset=
+#[tracing::instrument(name = "list_users", skip(pool), fields(request_id = %Uuid::new_v4()))]
+pub async fn list_users(
+    State(pool): State<PgPool>,
+    Query(params): Query<ListUsersParams>,
+) -> Result<(StatusCode, Json<UserList>), Error> {
+    let limit = params
+        .limit
+        .unwrap_or(DEFAULT_LIST_LIMIT)
+        .clamp(1, MAX_LIST_LIMIT);
+    let offset = params.offset.unwrap_or(0).max(0);
+
+    let items = sqlx::query_as::<_, User>(
+        r#"
+        SELECT id, name, email, age, created_at, updated_at
+        FROM users
+        ORDER BY id
+        LIMIT $1 OFFSET $2
+        "#,
+    )
+    .bind(limit)
+    .bind(offset)
+    .fetch_all(&pool)
+    .await?;
+
+    let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM users")
+        .fetch_one(&pool)
+        .await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(UserList {
+            items,
+            limit,
+            offset,
+            total,
+        }),
+    ))
 }
\ No newline at end of file
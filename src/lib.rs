@@ -0,0 +1,11 @@
+pub mod auth;
+pub mod config;
+pub mod db;
+pub mod domain;
+pub mod error;
+pub mod handlers;
+pub mod models;
+pub mod routes;
+pub mod startup;
+pub mod state;
+pub mod telemetry;
@@ -1,30 +1,41 @@
 use tokio::net::TcpListener;
 
-use rust_crud::{db, routes};
+use rust_crud::state::AppState;
+use rust_crud::telemetry::{get_subscriber, init_subscriber};
+use rust_crud::{config, db, startup};
 
 #[tokio::main]
 async fn main() {
     // Load environment variables from .env file
     dotenvy::dotenv().ok();
 
+    let subscriber = get_subscriber("rust_crud".into(), "info".into());
+    init_subscriber(subscriber);
+
+    // Load layered configuration (configuration/*.yaml + APP_* env overrides)
+    let settings = config::get_configuration().expect("Failed to read configuration");
+
     // Create database connection pool
-    let pool = db::create_pool()
+    let pool = db::create_pool(&settings.database)
         .await
         .expect("Failed to create database pool");
 
-    // Create the application router with database pool as shared state
-    let app = routes::create_routes().with_state(pool);
+    let state = AppState {
+        pool,
+        jwt: settings.jwt.clone(),
+    };
 
-    // Bind to localhost on port 3000
-    let listener = TcpListener::bind("127.0.0.1:3000")
+    // Bind to the configured host/port
+    let address = settings.application.address();
+    let listener = TcpListener::bind(&address)
         .await
         .expect("Failed to bind to address");
 
-    println!("🚀 Server listening on http://127.0.0.1:3000");
-    println!("📍 Health check: http://127.0.0.1:3000/health");
+    println!("🚀 Server listening on http://{}", address);
+    println!("📍 Health check: http://{}/health", address);
 
     // Start the server
-    axum::serve(listener, app)
+    startup::run(listener, state)
         .await
         .expect("Failed to start server");
 }
\ No newline at end of file
@@ -2,4 +2,4 @@
 pub mod user;
 
 // Re-export commonly used types
-pub use user::{CreateUser, UpdateUser, User};
+pub use user::{CreateUser, ListUsersParams, UpdateUser, User, UserList};
@@ -21,6 +21,7 @@ pub struct CreateUser {
     pub name: String,
     pub email: String,
     pub age: Option<i32>,
+    pub password: String,
 }
 
 /// DTO for updating a user (all fields optional)
@@ -30,3 +31,19 @@ pub struct UpdateUser {
     pub email: Option<String>,
     pub age: Option<i32>,
 }
+
+/// Query parameters for `GET /users`
+#[derive(Debug, Deserialize)]
+pub struct ListUsersParams {
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+/// Paginated response envelope for `GET /users`
+#[derive(Debug, Serialize)]
+pub struct UserList {
+    pub items: Vec<User>,
+    pub limit: i64,
+    pub offset: i64,
+    pub total: i64,
+}
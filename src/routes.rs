@@ -1,13 +1,39 @@
 // Route definitions
 
-use axum::{routing::get, routing::post, Router};
-use sqlx::PgPool;
+use axum::{
+    middleware,
+    routing::{get, patch, post},
+    Router,
+};
+use tower_http::trace::TraceLayer;
 
+use crate::auth::require_auth;
+use crate::config::JwtSettings;
 use crate::handlers;
+use crate::state::AppState;
 
 /// Create and configure all application routes
-pub fn create_routes() -> Router<PgPool> {
+///
+/// `POST /users` is registration and stays public - there is no way to
+/// obtain a token before an account exists. Only routes that mutate an
+/// *existing* user (update/delete) require a valid `Authorization: Bearer`
+/// token, enforced by the [`require_auth`] middleware.
+pub fn create_routes(jwt_settings: JwtSettings) -> Router<AppState> {
+    let protected = Router::new()
+        .route(
+            "/users/:id",
+            patch(handlers::users::update_user).delete(handlers::users::delete_user),
+        )
+        .route_layer(middleware::from_fn_with_state(jwt_settings, require_auth));
+
     Router::new()
         .route("/health", get(handlers::health::health))
-        .route("/users", post(handlers::users::create_user))
+        .route("/auth/login", post(handlers::auth::login))
+        .route(
+            "/users",
+            get(handlers::users::list_users).post(handlers::users::create_user),
+        )
+        .route("/users/:id", get(handlers::users::get_user))
+        .merge(protected)
+        .layer(TraceLayer::new_for_http())
 }
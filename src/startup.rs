@@ -0,0 +1,17 @@
+// Application bootstrap - wires the router to a listener
+//
+// Split out from `main` so integration tests can bind to an OS-assigned
+// port and drive the real router against an isolated database.
+
+use tokio::net::TcpListener;
+
+use crate::routes;
+use crate::state::AppState;
+
+/// Serve the application on `listener` using `state` as shared state
+///
+/// Runs until the server is shut down or errors.
+pub async fn run(listener: TcpListener, state: AppState) -> Result<(), std::io::Error> {
+    let app = routes::create_routes(state.jwt.clone()).with_state(state);
+    axum::serve(listener, app).await
+}
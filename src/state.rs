@@ -0,0 +1,24 @@
+// Shared application state handed to every route
+
+use axum::extract::FromRef;
+use sqlx::PgPool;
+
+use crate::config::JwtSettings;
+
+#[derive(Clone)]
+pub struct AppState {
+    pub pool: PgPool,
+    pub jwt: JwtSettings,
+}
+
+impl FromRef<AppState> for PgPool {
+    fn from_ref(state: &AppState) -> Self {
+        state.pool.clone()
+    }
+}
+
+impl FromRef<AppState> for JwtSettings {
+    fn from_ref(state: &AppState) -> Self {
+        state.jwt.clone()
+    }
+}
@@ -0,0 +1,119 @@
+// Integration tests for authentication
+
+mod helpers;
+
+use helpers::spawn_app;
+
+#[tokio::test]
+async fn login_with_correct_credentials_returns_a_token() {
+    let app = spawn_app().await;
+    let client = reqwest::Client::new();
+
+    client
+        .post(format!("{}/users", app.address))
+        .json(&serde_json::json!({
+            "name": "Ursula Le Guin",
+            "email": "ursula@domain.com",
+            "age": 60,
+            "password": "supersecret123"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    let response = client
+        .post(format!("{}/auth/login", app.address))
+        .json(&serde_json::json!({
+            "email": "ursula@domain.com",
+            "password": "supersecret123"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(200, response.status().as_u16());
+
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert!(body["token"].is_string());
+}
+
+#[tokio::test]
+async fn login_with_wrong_password_returns_401() {
+    let app = spawn_app().await;
+    let client = reqwest::Client::new();
+
+    client
+        .post(format!("{}/users", app.address))
+        .json(&serde_json::json!({
+            "name": "Ursula Le Guin",
+            "email": "ursula@domain.com",
+            "age": 60,
+            "password": "supersecret123"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    let response = client
+        .post(format!("{}/auth/login", app.address))
+        .json(&serde_json::json!({
+            "email": "ursula@domain.com",
+            "password": "wrong-password"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(401, response.status().as_u16());
+}
+
+#[tokio::test]
+async fn registration_does_not_require_a_bearer_token() {
+    let app = spawn_app().await;
+    let client = reqwest::Client::new();
+
+    let response = client
+        .post(format!("{}/users", app.address))
+        .json(&serde_json::json!({
+            "name": "Ursula Le Guin",
+            "email": "ursula@domain.com",
+            "age": 60,
+            "password": "supersecret123"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(201, response.status().as_u16());
+}
+
+#[tokio::test]
+async fn update_user_without_a_bearer_token_returns_401() {
+    let app = spawn_app().await;
+    let client = reqwest::Client::new();
+
+    let created: serde_json::Value = client
+        .post(format!("{}/users", app.address))
+        .json(&serde_json::json!({
+            "name": "Ursula Le Guin",
+            "email": "ursula@domain.com",
+            "age": 60,
+            "password": "supersecret123"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    let id = created["id"].as_i64().unwrap();
+
+    let response = client
+        .patch(format!("{}/users/{}", app.address, id))
+        .json(&serde_json::json!({ "age": 61 }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(401, response.status().as_u16());
+}
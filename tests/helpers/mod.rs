@@ -0,0 +1,72 @@
+// Test helper: spins up the app on a random port against a fresh, isolated database
+
+use sqlx::{Connection, Executor, PgConnection, PgPool};
+use tokio::net::TcpListener;
+use uuid::Uuid;
+
+use rust_crud::auth::Claims;
+use rust_crud::config::{get_configuration, DatabaseSettings, JwtSettings};
+use rust_crud::startup::run;
+use rust_crud::state::AppState;
+
+pub struct TestApp {
+    pub address: String,
+    pub pool: PgPool,
+    pub jwt: JwtSettings,
+}
+
+impl TestApp {
+    /// Mint a bearer token for the given user id, for hitting protected routes
+    pub fn auth_header(&self, user_id: i32) -> String {
+        let token = Claims::new(user_id, &self.jwt)
+            .encode(&self.jwt)
+            .expect("Failed to encode test token");
+        format!("Bearer {}", token)
+    }
+}
+
+/// Bind the app to an OS-assigned port and serve it against a freshly
+/// created, migrated database named after a random UUID.
+pub async fn spawn_app() -> TestApp {
+    let mut configuration = get_configuration().expect("Failed to read configuration");
+    configuration.database.database_name = Uuid::new_v4().to_string();
+
+    let pool = configure_database(&configuration.database).await;
+
+    let listener = TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind random port");
+    let address = format!("http://{}", listener.local_addr().unwrap());
+
+    let state = AppState {
+        pool: pool.clone(),
+        jwt: configuration.jwt.clone(),
+    };
+    let jwt = configuration.jwt.clone();
+    tokio::spawn(async move {
+        run(listener, state).await.expect("Server error");
+    });
+
+    TestApp { address, pool, jwt }
+}
+
+/// Create a fresh logical database and run migrations against it
+async fn configure_database(config: &DatabaseSettings) -> PgPool {
+    let mut connection = PgConnection::connect_with(&config.without_db())
+        .await
+        .expect("Failed to connect to the maintenance database");
+    connection
+        .execute(format!(r#"CREATE DATABASE "{}";"#, config.database_name).as_str())
+        .await
+        .expect("Failed to create test database");
+
+    let pool = PgPool::connect_with(config.with_db())
+        .await
+        .expect("Failed to connect to the test database");
+    sqlx::migrate!("./migrations")
+        .run(&pool)
+        .await
+        .expect("Failed to migrate the test database");
+
+    pool
+}
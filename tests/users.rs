@@ -0,0 +1,187 @@
+// Integration tests for the users CRUD lifecycle
+
+mod helpers;
+
+use helpers::spawn_app;
+use sqlx::Row;
+
+#[tokio::test]
+async fn create_user_returns_201_and_persists_the_row() {
+    let app = spawn_app().await;
+    let client = reqwest::Client::new();
+
+    let response = client
+        .post(format!("{}/users", app.address))
+        .json(&serde_json::json!({
+            "name": "Ursula Le Guin",
+            "email": "ursula@domain.com",
+            "age": 60,
+            "password": "supersecret123"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(201, response.status().as_u16());
+
+    let saved = sqlx::query("SELECT name, email FROM users")
+        .fetch_one(&app.pool)
+        .await
+        .expect("Failed to fetch saved user");
+
+    assert_eq!(saved.get::<String, _>("name"), "Ursula Le Guin");
+    assert_eq!(saved.get::<String, _>("email"), "ursula@domain.com");
+}
+
+#[tokio::test]
+async fn create_user_with_duplicate_email_returns_409() {
+    let app = spawn_app().await;
+    let client = reqwest::Client::new();
+    let body = serde_json::json!({
+        "name": "Ursula Le Guin",
+        "email": "ursula@domain.com",
+        "age": 60,
+        "password": "supersecret123"
+    });
+    client
+        .post(format!("{}/users", app.address))
+        .json(&body)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    let response = client
+        .post(format!("{}/users", app.address))
+        .json(&body)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(409, response.status().as_u16());
+}
+
+#[tokio::test]
+async fn get_missing_user_returns_404() {
+    let app = spawn_app().await;
+    let client = reqwest::Client::new();
+
+    let response = client
+        .get(format!("{}/users/999999", app.address))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(404, response.status().as_u16());
+}
+
+#[tokio::test]
+async fn update_user_as_self_returns_200() {
+    let app = spawn_app().await;
+    let client = reqwest::Client::new();
+
+    let created: serde_json::Value = client
+        .post(format!("{}/users", app.address))
+        .json(&serde_json::json!({
+            "name": "Ursula Le Guin",
+            "email": "ursula@domain.com",
+            "age": 60,
+            "password": "supersecret123"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    let id = created["id"].as_i64().unwrap();
+
+    let response = client
+        .patch(format!("{}/users/{}", app.address, id))
+        .header("Authorization", app.auth_header(id as i32))
+        .json(&serde_json::json!({ "age": 61 }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(200, response.status().as_u16());
+}
+
+#[tokio::test]
+async fn update_user_as_a_different_caller_returns_403() {
+    let app = spawn_app().await;
+    let client = reqwest::Client::new();
+
+    let created: serde_json::Value = client
+        .post(format!("{}/users", app.address))
+        .json(&serde_json::json!({
+            "name": "Ursula Le Guin",
+            "email": "ursula@domain.com",
+            "age": 60,
+            "password": "supersecret123"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    let id = created["id"].as_i64().unwrap();
+
+    let response = client
+        .patch(format!("{}/users/{}", app.address, id))
+        .header("Authorization", app.auth_header((id + 1) as i32))
+        .json(&serde_json::json!({ "age": 61 }))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(403, response.status().as_u16());
+}
+
+#[tokio::test]
+async fn delete_user_as_a_different_caller_returns_403() {
+    let app = spawn_app().await;
+    let client = reqwest::Client::new();
+
+    let created: serde_json::Value = client
+        .post(format!("{}/users", app.address))
+        .json(&serde_json::json!({
+            "name": "Ursula Le Guin",
+            "email": "ursula@domain.com",
+            "age": 60,
+            "password": "supersecret123"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    let id = created["id"].as_i64().unwrap();
+
+    let response = client
+        .delete(format!("{}/users/{}", app.address, id))
+        .header("Authorization", app.auth_header((id + 1) as i32))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(403, response.status().as_u16());
+}
+
+#[tokio::test]
+async fn health_check_reports_database_connected() {
+    let app = spawn_app().await;
+    let client = reqwest::Client::new();
+
+    let response = client
+        .get(format!("{}/health", app.address))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(200, response.status().as_u16());
+
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["database"], "connected");
+}